@@ -0,0 +1,117 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+/// Ranks resolved addresses so the "best" one is tried first: global IPv4,
+/// then IPv6, with link-local/APIPA (169.254.x.x) addresses pushed to the
+/// back since they're almost never reachable off-host.
+pub fn rank_addresses(addresses: impl IntoIterator<Item = IpAddr>) -> Vec<IpAddr> {
+    let mut ranked: Vec<IpAddr> = addresses.into_iter().collect();
+    ranked.sort_by_key(address_priority);
+    ranked
+}
+
+fn address_priority(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(v4) if is_link_local_v4(v4) => 2,
+        IpAddr::V4(_) => 0,
+        IpAddr::V6(v6) if is_link_local_v6(v6) => 3,
+        IpAddr::V6(_) => 1,
+    }
+}
+
+fn is_link_local_v4(addr: &Ipv4Addr) -> bool {
+    let octets = addr.octets();
+    octets[0] == 169 && octets[1] == 254
+}
+
+fn is_link_local_v6(addr: &Ipv6Addr) -> bool {
+    (addr.segments()[0] & 0xffc0) == 0xfe80
+}
+
+/// Tries a short TCP connect to `addr:port`, used to confirm a candidate is
+/// actually reachable instead of just resolved.
+pub fn probe(addr: IpAddr, port: u16, timeout: Duration) -> bool {
+    TcpStream::connect_timeout(&SocketAddr::new(addr, port), timeout).is_ok()
+}
+
+/// Returns the first ranked candidate that accepts a TCP connection before
+/// `deadline`, probing each candidate for at most `per_candidate_timeout`
+/// (further capped by however much time remains before `deadline`), so a
+/// long candidate list can't run the total probe time past the caller's
+/// overall discovery budget.
+pub fn select_reachable(
+    candidates: &[IpAddr],
+    port: u16,
+    per_candidate_timeout: Duration,
+    deadline: Instant,
+) -> Option<IpAddr> {
+    for addr in candidates {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let timeout = per_candidate_timeout.min(remaining);
+        if probe(*addr, port, timeout) {
+            return Some(*addr);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v4(octets: [u8; 4]) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::from(octets))
+    }
+
+    fn v6(segments: [u16; 8]) -> IpAddr {
+        IpAddr::V6(Ipv6Addr::from(segments))
+    }
+
+    #[test]
+    fn ranks_global_ipv4_before_ipv6_before_link_local() {
+        let link_local_v6 = v6([0xfe80, 0, 0, 0, 0, 0, 0, 1]);
+        let link_local_v4 = v4([169, 254, 1, 1]);
+        let global_v6 = v6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]);
+        let global_v4 = v4([192, 168, 1, 10]);
+
+        let ranked = rank_addresses(vec![
+            link_local_v6,
+            link_local_v4,
+            global_v6,
+            global_v4,
+        ]);
+
+        assert_eq!(
+            ranked,
+            vec![global_v4, global_v6, link_local_v4, link_local_v6]
+        );
+    }
+
+    #[test]
+    fn ipv4_link_local_boundary() {
+        assert!(is_link_local_v4(&Ipv4Addr::new(169, 254, 0, 0)));
+        assert!(is_link_local_v4(&Ipv4Addr::new(169, 254, 255, 255)));
+        assert!(!is_link_local_v4(&Ipv4Addr::new(169, 253, 255, 255)));
+        assert!(!is_link_local_v4(&Ipv4Addr::new(169, 255, 0, 0)));
+        assert!(!is_link_local_v4(&Ipv4Addr::new(192, 168, 1, 1)));
+    }
+
+    #[test]
+    fn ipv6_link_local_boundary() {
+        assert!(is_link_local_v6(&Ipv6Addr::new(
+            0xfe80, 0, 0, 0, 0, 0, 0, 0
+        )));
+        assert!(is_link_local_v6(&Ipv6Addr::new(
+            0xfebf, 0, 0, 0, 0, 0, 0, 1
+        )));
+        assert!(!is_link_local_v6(&Ipv6Addr::new(
+            0xfec0, 0, 0, 0, 0, 0, 0, 1
+        )));
+        assert!(!is_link_local_v6(&Ipv6Addr::new(
+            0x2001, 0xdb8, 0, 0, 0, 0, 0, 1
+        )));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use mdns_sd::{ServiceDaemon, ServiceInfo};
+use serde::Deserialize;
+
+use crate::txt::{KEY_COURT, KEY_PROTOCOL_VERSION, KEY_VENUE};
+
+const SERVICE_TYPE: &str = "_clubscore._tcp.local.";
+
+/// Holds the `ServiceDaemon` registered for this instance's own
+/// advertisement, for as long as advertising is active. `None` means we
+/// aren't currently advertising.
+#[derive(Default)]
+pub struct AdvertiseState(pub Mutex<Option<ServiceDaemon>>);
+
+/// What a display instance wants to announce about itself so a controller
+/// app can find and bind to it without manual IP entry.
+#[derive(Deserialize)]
+pub struct AdvertiseOptions {
+    pub port: u16,
+    pub display_name: String,
+    pub venue: Option<String>,
+    pub court: Option<String>,
+    pub protocol_version: Option<String>,
+}
+
+#[tauri::command]
+pub fn advertise_scoreboard(
+    state: tauri::State<AdvertiseState>,
+    options: AdvertiseOptions,
+) -> Result<(), String> {
+    let mut daemon = state
+        .0
+        .lock()
+        .map_err(|_| "advertise state poisoned".to_string())?;
+    if daemon.is_some() {
+        // Already advertising; nothing to do.
+        return Ok(());
+    }
+
+    let mdns = ServiceDaemon::new().map_err(|err| err.to_string())?;
+
+    let hostname = format!("{}.local.", options.display_name.replace(' ', "-"));
+    let mut properties: HashMap<String, String> = HashMap::new();
+    properties.insert("display_name".to_string(), options.display_name.clone());
+    if let Some(venue) = &options.venue {
+        properties.insert(KEY_VENUE.to_string(), venue.clone());
+    }
+    if let Some(court) = &options.court {
+        properties.insert(KEY_COURT.to_string(), court.clone());
+    }
+    properties.insert(
+        KEY_PROTOCOL_VERSION.to_string(),
+        options.protocol_version.unwrap_or_else(|| "1".to_string()),
+    );
+
+    // An empty host-ip only gets populated with this machine's real
+    // interface addresses once `enable_addr_auto()` is called; otherwise the
+    // registered record carries no A/AAAA addresses and is unresolvable.
+    let service_info = ServiceInfo::new(
+        SERVICE_TYPE,
+        &options.display_name,
+        &hostname,
+        "",
+        options.port,
+        properties,
+    )
+    .map_err(|err| err.to_string())?
+    .enable_addr_auto();
+
+    mdns.register(service_info).map_err(|err| err.to_string())?;
+
+    *daemon = Some(mdns);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_advertising(state: tauri::State<AdvertiseState>) -> Result<(), String> {
+    let mut daemon = state
+        .0
+        .lock()
+        .map_err(|_| "advertise state poisoned".to_string())?;
+    if let Some(mdns) = daemon.take() {
+        mdns.shutdown().map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+
+    use super::SERVICE_TYPE;
+
+    /// Registers a service the same way `advertise_scoreboard` does and
+    /// confirms a browser actually resolves a non-empty address list for
+    /// it, guarding against the empty-host-ip/no-`enable_addr_auto()`
+    /// regression this advertising path previously had.
+    #[test]
+    fn registered_service_resolves_with_addresses() {
+        let mdns = ServiceDaemon::new().expect("failed to create mdns daemon");
+
+        let service_info = ServiceInfo::new(
+            SERVICE_TYPE,
+            "smoke-test-instance",
+            "smoke-test.local.",
+            "",
+            9999,
+            std::collections::HashMap::new(),
+        )
+        .expect("failed to build ServiceInfo")
+        .enable_addr_auto();
+        mdns.register(service_info).expect("failed to register service");
+
+        let receiver = mdns.browse(SERVICE_TYPE).expect("failed to browse");
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let mut resolved_addresses = Vec::new();
+
+        while std::time::Instant::now() < deadline && resolved_addresses.is_empty() {
+            if let Ok(ServiceEvent::ServiceResolved(info)) =
+                receiver.recv_timeout(Duration::from_millis(300))
+            {
+                if info.get_fullname().starts_with("smoke-test-instance") {
+                    resolved_addresses = info.get_addresses().iter().copied().collect();
+                }
+            }
+        }
+
+        let _ = mdns.shutdown();
+        assert!(
+            !resolved_addresses.is_empty(),
+            "expected the registered instance to resolve with at least one address"
+        );
+    }
+}
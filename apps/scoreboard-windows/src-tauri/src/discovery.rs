@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use mdns_sd::{ServiceDaemon, ServiceEvent};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::service;
+
+const SERVICE_TYPE: &str = "_clubscore._tcp.local.";
+
+/// Emitted on `clubscore://server-found` whenever mDNS resolves a scoreboard
+/// that we haven't already seen (keyed by its service fullname), or one whose
+/// resolved info has changed since it was last emitted.
+#[derive(Serialize, Clone, PartialEq)]
+pub struct ServerFoundEvent {
+    pub fullname: String,
+    pub host: String,
+    pub port: u16,
+    pub addresses: Vec<String>,
+    pub properties: HashMap<String, String>,
+    pub venue: Option<String>,
+    pub court: Option<String>,
+    pub match_id: Option<String>,
+    pub protocol_version: Option<String>,
+}
+
+/// Emitted on `clubscore://server-lost` when a previously-seen scoreboard
+/// disappears from the network.
+#[derive(Serialize, Clone)]
+pub struct ServerLostEvent {
+    pub fullname: String,
+}
+
+/// Holds the background `ServiceDaemon` for as long as discovery is running.
+/// `None` means discovery is currently stopped.
+#[derive(Default)]
+pub struct DiscoveryState(pub Mutex<Option<ServiceDaemon>>);
+
+#[tauri::command]
+pub fn start_discovery(
+    app_handle: AppHandle,
+    state: tauri::State<DiscoveryState>,
+) -> Result<(), String> {
+    let mut daemon = state
+        .0
+        .lock()
+        .map_err(|_| "discovery state poisoned".to_string())?;
+    if daemon.is_some() {
+        // Already running; nothing to do.
+        return Ok(());
+    }
+
+    let mdns = ServiceDaemon::new().map_err(|err| err.to_string())?;
+    let receiver = mdns.browse(SERVICE_TYPE).map_err(|err| err.to_string())?;
+
+    tauri::async_runtime::spawn(async move {
+        let mut known: HashMap<String, ServerFoundEvent> = HashMap::new();
+
+        while let Ok(event) = receiver.recv_async().await {
+            match event {
+                ServiceEvent::ServiceResolved(info) => {
+                    let resolved = service::resolve(&info);
+
+                    let found = ServerFoundEvent {
+                        fullname: resolved.fullname,
+                        host: resolved.host,
+                        port: resolved.port,
+                        addresses: resolved.addresses,
+                        properties: resolved.properties,
+                        venue: resolved.venue,
+                        court: resolved.court,
+                        match_id: resolved.match_id,
+                        protocol_version: resolved.protocol_version,
+                    };
+
+                    // mDNS re-resolves already-known services on TTL refresh;
+                    // only emit when this is a new server or its info changed.
+                    let is_new_or_changed =
+                        known.get(&found.fullname) != Some(&found);
+                    known.insert(found.fullname.clone(), found.clone());
+                    if is_new_or_changed {
+                        let _ = app_handle.emit_all("clubscore://server-found", found);
+                    }
+                }
+                ServiceEvent::ServiceRemoved(_service_type, fullname) => {
+                    if known.remove(&fullname).is_some() {
+                        let _ = app_handle
+                            .emit_all("clubscore://server-lost", ServerLostEvent { fullname });
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+
+    *daemon = Some(mdns);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_discovery(state: tauri::State<DiscoveryState>) -> Result<(), String> {
+    let mut daemon = state
+        .0
+        .lock()
+        .map_err(|_| "discovery state poisoned".to_string())?;
+    if let Some(mdns) = daemon.take() {
+        mdns.shutdown().map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
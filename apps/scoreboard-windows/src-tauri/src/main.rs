@@ -1,39 +1,130 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod address;
+mod advertise;
+mod discovery;
+mod service;
+mod state;
+mod txt;
+
+use advertise::AdvertiseState;
+use discovery::DiscoveryState;
 use mdns_sd::{ServiceDaemon, ServiceEvent};
 use serde::Serialize;
+use state::AppState;
+use std::collections::HashMap;
+use std::net::{TcpStream, ToSocketAddrs};
 use std::time::{Duration, Instant};
+use tauri::Manager;
 
 #[derive(Serialize)]
 struct DiscoveryResult {
+    fullname: Option<String>,
     host: String,
     port: u16,
+    addresses: Vec<String>,
+    properties: HashMap<String, String>,
+    venue: Option<String>,
+    court: Option<String>,
+    match_id: Option<String>,
+    protocol_version: Option<String>,
+}
+
+/// Tries to reconnect to the host:port this instance last connected to,
+/// probing it directly instead of paying for a fresh mDNS browse. No TXT
+/// metadata is available for this path since it skips mDNS resolution.
+fn probe_saved_server(host: &str, port: u16, timeout: Duration) -> Option<DiscoveryResult> {
+    let addr = (host, port).to_socket_addrs().ok()?.next()?;
+    TcpStream::connect_timeout(&addr, timeout).ok()?;
+    Some(DiscoveryResult {
+        fullname: None,
+        host: host.to_string(),
+        port,
+        addresses: vec![host.to_string()],
+        properties: HashMap::new(),
+        venue: None,
+        court: None,
+        match_id: None,
+        protocol_version: None,
+    })
 }
 
 #[tauri::command]
-fn discover_server(timeout_ms: Option<u64>) -> Result<Option<DiscoveryResult>, String> {
+fn discover_server(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<AppState>,
+    timeout_ms: Option<u64>,
+    probe_timeout_ms: Option<u64>,
+) -> Result<Option<DiscoveryResult>, String> {
+    let (saved, configured_timeout_ms) = {
+        let config = state
+            .config
+            .read()
+            .map_err(|_| "config lock poisoned".to_string())?;
+        (
+            config.last_host.clone().zip(config.last_port),
+            config.discovery_timeout_ms,
+        )
+    };
+
+    // Explicit argument wins, then the persisted preference, then the
+    // built-in default.
+    let timeout = Duration::from_millis(timeout_ms.or(configured_timeout_ms).unwrap_or(2500));
+
+    if let Some((host, port)) = saved {
+        if let Some(result) = probe_saved_server(&host, port, Duration::from_millis(500)) {
+            return Ok(Some(result));
+        }
+    }
+
     let mdns = ServiceDaemon::new().map_err(|err| err.to_string())?;
     let receiver = mdns
         .browse("_clubscore._tcp.local.")
         .map_err(|err| err.to_string())?;
 
-    let timeout = Duration::from_millis(timeout_ms.unwrap_or(2500));
     let deadline = Instant::now() + timeout;
     let mut found: Option<DiscoveryResult> = None;
 
     while Instant::now() < deadline {
         match receiver.recv_timeout(Duration::from_millis(300)) {
             Ok(ServiceEvent::ServiceResolved(info)) => {
-                let host = info
-                    .get_addresses()
-                    .iter()
-                    .next()
-                    .map(|addr| addr.to_string())
-                    .unwrap_or_else(|| info.get_hostname().to_string());
+                let resolved = service::resolve(&info);
+
+                // Only override the shared "ranked address / hostname"
+                // fallback host when the caller asked for a reachability
+                // probe and there's actually something to probe; otherwise
+                // keep `resolved.host`, which already falls back to the
+                // advertised hostname when no addresses resolved.
+                let host = match probe_timeout_ms {
+                    Some(ms) if !resolved.ranked_ips.is_empty() => {
+                        match address::select_reachable(
+                            &resolved.ranked_ips,
+                            resolved.port,
+                            Duration::from_millis(ms),
+                            deadline,
+                        ) {
+                            Some(addr) => addr.to_string(),
+                            None => {
+                                // Resolved, but none of its addresses
+                                // answered the probe; keep listening in
+                                // case another instance responds.
+                                continue;
+                            }
+                        }
+                    }
+                    _ => resolved.host,
+                };
 
                 found = Some(DiscoveryResult {
+                    fullname: Some(resolved.fullname),
                     host,
-                    port: info.get_port(),
+                    port: resolved.port,
+                    addresses: resolved.addresses,
+                    properties: resolved.properties,
+                    venue: resolved.venue,
+                    court: resolved.court,
+                    match_id: resolved.match_id,
+                    protocol_version: resolved.protocol_version,
                 });
                 break;
             }
@@ -43,12 +134,37 @@ fn discover_server(timeout_ms: Option<u64>) -> Result<Option<DiscoveryResult>, S
     }
 
     let _ = mdns.shutdown();
+
+    if let Some(result) = &found {
+        let mut config = state
+            .config
+            .write()
+            .map_err(|_| "config lock poisoned".to_string())?;
+        config.last_host = Some(result.host.clone());
+        config.last_port = Some(result.port);
+        state::persist_config(&app_handle, &config)?;
+    }
+
     Ok(found)
 }
 
 fn main() {
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![discover_server])
+        .manage(DiscoveryState::default())
+        .manage(AdvertiseState::default())
+        .setup(|app| {
+            app.manage(AppState::new(&app.handle()));
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            discover_server,
+            discovery::start_discovery,
+            discovery::stop_discovery,
+            state::get_config,
+            state::save_config,
+            advertise::advertise_scoreboard,
+            advertise::stop_advertising,
+        ])
         .run(tauri::generate_context!())
         .expect("error while running clubscore scoreboard app");
 }
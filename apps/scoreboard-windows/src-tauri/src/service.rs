@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use mdns_sd::ServiceInfo;
+
+use crate::{address, txt};
+
+/// Canonical host/port/TXT reconstruction shared by the one-shot
+/// `discover_server` command and the continuous `discovery::start_discovery`
+/// loop, so the two discovery paths can't drift on how they pick a host or
+/// fall back when a service resolved with no usable address records.
+pub struct ResolvedService {
+    pub fullname: String,
+    pub host: String,
+    pub port: u16,
+    pub addresses: Vec<String>,
+    pub ranked_ips: Vec<IpAddr>,
+    pub properties: HashMap<String, String>,
+    pub venue: Option<String>,
+    pub court: Option<String>,
+    pub match_id: Option<String>,
+    pub protocol_version: Option<String>,
+}
+
+pub fn resolve(info: &ServiceInfo) -> ResolvedService {
+    let ranked_ips = address::rank_addresses(info.get_addresses().iter().copied());
+    let host = ranked_ips
+        .first()
+        .map(|addr| addr.to_string())
+        .unwrap_or_else(|| info.get_hostname().to_string());
+    let addresses: Vec<String> = ranked_ips.iter().map(|addr| addr.to_string()).collect();
+    let metadata = txt::read_metadata(info);
+
+    ResolvedService {
+        fullname: info.get_fullname().to_string(),
+        host,
+        port: info.get_port(),
+        addresses,
+        ranked_ips,
+        properties: metadata.properties,
+        venue: metadata.venue,
+        court: metadata.court,
+        match_id: metadata.match_id,
+        protocol_version: metadata.protocol_version,
+    }
+}
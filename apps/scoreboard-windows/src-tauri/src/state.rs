@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Persisted settings for this scoreboard instance. Saved as JSON in the
+/// Tauri app-config directory so a restart can reconnect without a fresh
+/// mDNS browse.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct Config {
+    pub last_host: Option<String>,
+    pub last_port: Option<u16>,
+    pub preferred_venue: Option<String>,
+    pub preferred_court: Option<String>,
+    pub discovery_timeout_ms: Option<u64>,
+}
+
+/// Managed app-wide state. Currently just the persisted config, guarded by
+/// an `RwLock` since commands may read it far more often than they write it.
+pub struct AppState {
+    pub config: RwLock<Config>,
+}
+
+impl AppState {
+    pub fn new(app_handle: &AppHandle) -> Self {
+        let config = load_config(app_handle).unwrap_or_default();
+        Self {
+            config: RwLock::new(config),
+        }
+    }
+}
+
+fn config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_config_dir()
+        .ok_or_else(|| "could not resolve app config dir".to_string())?;
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    Ok(dir.join(CONFIG_FILE_NAME))
+}
+
+fn load_config(app_handle: &AppHandle) -> Result<Config, String> {
+    let path = config_path(app_handle)?;
+    let bytes = fs::read(&path).map_err(|err| err.to_string())?;
+    serde_json::from_slice(&bytes).map_err(|err| err.to_string())
+}
+
+pub fn persist_config(app_handle: &AppHandle, config: &Config) -> Result<(), String> {
+    let path = config_path(app_handle)?;
+    let bytes = serde_json::to_vec_pretty(config).map_err(|err| err.to_string())?;
+    fs::write(path, bytes).map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub fn get_config(state: tauri::State<AppState>) -> Result<Config, String> {
+    let config = state
+        .config
+        .read()
+        .map_err(|_| "config lock poisoned".to_string())?;
+    Ok(config.clone())
+}
+
+#[tauri::command]
+pub fn save_config(
+    app_handle: AppHandle,
+    state: tauri::State<AppState>,
+    config: Config,
+) -> Result<(), String> {
+    {
+        let mut guard = state
+            .config
+            .write()
+            .map_err(|_| "config lock poisoned".to_string())?;
+        *guard = config.clone();
+    }
+    persist_config(&app_handle, &config)
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use mdns_sd::ServiceInfo;
+
+/// TXT keys a scoreboard advertisement is expected to carry. Any other keys
+/// still end up in `properties` so future additions don't need code changes
+/// here.
+pub(crate) const KEY_VENUE: &str = "venue";
+pub(crate) const KEY_COURT: &str = "court";
+pub(crate) const KEY_MATCH_ID: &str = "match_id";
+pub(crate) const KEY_PROTOCOL_VERSION: &str = "protocol_version";
+
+/// The TXT metadata for a resolved `_clubscore._tcp.local.` service, both as
+/// a raw key/value map and as the typed fields a controller cares about most.
+pub struct ServiceMetadata {
+    pub properties: HashMap<String, String>,
+    pub venue: Option<String>,
+    pub court: Option<String>,
+    pub match_id: Option<String>,
+    pub protocol_version: Option<String>,
+}
+
+pub fn read_metadata(info: &ServiceInfo) -> ServiceMetadata {
+    let properties: HashMap<String, String> = info
+        .get_properties()
+        .iter()
+        .map(|prop| (prop.key().to_string(), prop.val_str().to_string()))
+        .collect();
+
+    let venue = properties.get(KEY_VENUE).cloned();
+    let court = properties.get(KEY_COURT).cloned();
+    let match_id = properties.get(KEY_MATCH_ID).cloned();
+    let protocol_version = properties.get(KEY_PROTOCOL_VERSION).cloned();
+
+    ServiceMetadata {
+        properties,
+        venue,
+        court,
+        match_id,
+        protocol_version,
+    }
+}